@@ -161,6 +161,18 @@ where
         res
     }
 
+    /// Returns the time left until the open circuit breaker allows calls again.
+    ///
+    /// It returns `None` if the circuit breaker is not in the open state, and `Duration::ZERO`
+    /// if the open interval has already expired but no call was requested yet.
+    pub fn time_until_half_open(&self) -> Option<Duration> {
+        let shared = self.inner.shared.lock();
+        match shared.state {
+            State::Open(until, _) => Some(until.saturating_duration_since(clock::now())),
+            _ => None,
+        }
+    }
+
     /// Reset state machine to Closed
     ///
     pub fn reset(&self) {
@@ -326,6 +338,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn time_until_half_open() {
+        clock::freeze(move |time| {
+            let backoff = backoff::constant(5.seconds());
+            let policy = consecutive_failures(1, backoff);
+            let state_machine = StateMachine::new(policy, ());
+
+            assert_eq!(None, state_machine.time_until_half_open());
+
+            state_machine.on_error();
+            assert_eq!(Some(5.seconds()), state_machine.time_until_half_open());
+
+            time.advance(2.seconds());
+            assert_eq!(Some(3.seconds()), state_machine.time_until_half_open());
+
+            time.advance(4.seconds());
+            assert_eq!(Some(Duration::ZERO), state_machine.time_until_half_open());
+
+            assert!(state_machine.is_call_permitted());
+            assert_eq!(None, state_machine.time_until_half_open());
+        });
+    }
+
     #[derive(Debug)]
     enum State {
         Open,